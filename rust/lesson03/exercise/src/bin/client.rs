@@ -1,12 +1,10 @@
 use std::env;
 use reqwest::Client;
 use std::collections::HashMap;
-use exercise::init_tracer;
-
-use opentelemetry::{
-    global, trace::{Span, TraceContextExt, Tracer, FutureExt}, KeyValue,
-};
+use exercise::{init_tracer_with_tracing, traced_get};
 
+use tracing::instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 const FORMAT_URL: &str = "http://localhost:8081/format";
 const PUBLISH_URL: &str = "http://localhost:8082/publish";
@@ -20,105 +18,54 @@ async fn main()-> Result<(), reqwest::Error>{
     }
     let hello_to = args[1].clone();
 
-    // initializing the OpenTelemetry TracerProvider with the service name "hello-world"
-    let tp = init_tracer("hello-world")
+    // initializing the OpenTelemetry TracerProvider with the service name "hello-world",
+    // bridged to the `tracing` crate so `#[instrument]` below produces real OTel spans
+    let tp = init_tracer_with_tracing("hello-world")
         .expect("Error initializing tracer");
 
-    // creating a named instance of Tracer via the configured GlobalTracerProvider
-    let tracer = global::tracer("say-hello-tracer");
-
-    // creating a new span named "say-hello".
-    let mut span =  tracer.start("say-hello");
-
-    // adding an attribute to the span
-    span.set_attribute(KeyValue::new("hello-to", hello_to.to_string()));
-
-    // getting the context with the current span included
-    let context_main = opentelemetry::Context::default().with_span(span);
-
-    // calling the `format_string` function with the span context `context_main` to maintain proper parent-child relationship
-    let formatted_str = FutureExt::with_context(format_string(&hello_to), context_main.clone()).await?;
-
-    // calling the `publish_string` function with the span context `context_main` to maintain proper parent-child relationship
-    FutureExt::with_context(publish_string(&formatted_str), context_main.clone()).await?;
-
-    // ending the current span
-    context_main.span().end();
+    say_hello(&hello_to).await?;
 
     // shutting down the tracer provider to ensure all spans are flushed.
     tp.shutdown().expect("TracerProvider should shutdown successfully");
     Ok(())
 }
 
+/// the entry-point span for the whole request, replacing the manual
+/// `tracer.start("say-hello")` / `span.set_attribute(...)` / `span.end()` dance: `instrument`
+/// starts and ends the span for us and captures `hello_to` as a span attribute.
+#[instrument(fields(hello_to = %hello_to))]
+async fn say_hello(hello_to: &str) -> Result<(), reqwest::Error> {
+    let formatted_str = format_string(hello_to).await?;
+    publish_string(&formatted_str).await?;
+    Ok(())
+}
 
+#[instrument]
 async fn format_string(hello_to: &str) -> Result<String, reqwest::Error>{
-    // retrieve or create a named tracer
-    let tracer = global::tracer("say-hello-tracer");
-
-    // Start a new span named "formatString".
-    let mut span =  tracer.start("formatString");
-
-    // preparing to send an http get request to the "formatter" service
     let client = Client::new();
     let mut params = HashMap::new();
     params.insert("hello_to".to_string(), hello_to.to_string());
 
-    //sending a get request
-    match client.get(FORMAT_URL).query(&params).send().await{
-        Err(err) =>{
-            // recording the error in the span
-            span.record_error(&err);
-
-            // ending the span
-            span.end();
-
-            return Err(err);
-        },
-        Ok(resp) =>{
-            let hello_str = resp.text().await?;
+    // `traced_get` builds the request, injects the current context into its headers, sends
+    // it, and records a client span for the call
+    let resp = traced_get(&client, FORMAT_URL, &params, &tracing::Span::current().context()).await?;
+    let hello_str = resp.text().await?;
 
-            // adding an event to the span indicating a successful response was received
-            span.add_event("format-event-response", 
-            vec![
-                KeyValue::new("format-response", format!("string-format: {}", hello_str)),
-                ]);
+    // recording a successful response as a span event
+    tracing::info!(format_response = %hello_str, "received formatted string");
 
-            // ending the span
-            span.end();
-            
-            Ok(hello_str)
-        }   
-    }
+    Ok(hello_str)
 }
 
+#[instrument]
 async fn publish_string(hello_str: &str) -> Result<(), reqwest::Error>{
-    // retrieve or create a named tracer
-    let tracer = global::tracer("say-hello-tracer");
-
-    // Start a new span named "printHello".
-    let mut span =  tracer.start("printHello");
-
-    // preparing to send an http get request to the "publisher" service
     let client = Client::new();
     let mut params = HashMap::new();
     params.insert("hello_str".to_string(), hello_str.to_string());
 
-    // sending a get request
-    match client.get(PUBLISH_URL).query(&params).send().await{
-        Err(err) =>{
-            // recording the error in the span
-            span.record_error(&err);
-            
-            // ending the span
-            span.end();
-            
-            return Err(err);
-        },
-        Ok(_) =>{
-            // ending the span
-            span.end();
-            
-            Ok(())
-        }   
-    }
-}
\ No newline at end of file
+    // `traced_get` builds the request, injects the current context into its headers, sends
+    // it, and records a client span for the call
+    traced_get(&client, PUBLISH_URL, &params, &tracing::Span::current().context()).await?;
+
+    Ok(())
+}
@@ -2,22 +2,53 @@ use axum::{extract::Query, routing::get, Router};
 use tokio::net::TcpListener;
 use std::collections::HashMap;
 
+use opentelemetry::{trace::TraceContextExt, Context};
+
+use exercise::{init_meter, init_tracer, middleware::OpenTelemetryLayer, shutdown_signal};
+
 async fn format_handler(Query(params): Query<HashMap<String, String>>) -> String {
-    match params.get("hello_to"){
+    // `OpenTelemetryLayer` already started the span for this request; pull it from the
+    // current context instead of extracting headers and starting a span by hand
+    let cx = Context::current();
+
+    let resp = match params.get("hello_to"){
         Some(hello_to) =>{
             format!("Hello, {}!", hello_to)
         },
         None => "Hello, !".to_string()
-    }
+    };
+
+    // adding an event to the span indicating that the string was properly formatted
+    cx.span().add_event("format-event-response", vec![]);
+
+    resp
 }
 
 
 
 #[tokio::main]
 async fn main() {
-    let app = Router::new().route("/format", get(format_handler));
-    
+    // initializing the OpenTelemetry TracerProvider and MeterProvider with the service name
+    // "formatter"; `OpenTelemetryLayer` records request count/duration through the meter
+    let tp = init_tracer("formatter")
+        .expect("Error initializing tracer");
+    let mp = init_meter("formatter")
+        .expect("Error initializing meter");
+
+    let app = Router::new()
+        .route("/format", get(format_handler))
+        .route_layer(OpenTelemetryLayer::new());
+
     let listener = TcpListener::bind("0.0.0.0:8081").await.unwrap();
-    
-    axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+
+    // draining in-flight requests on SIGINT/SIGTERM instead of blocking forever, so the
+    // shutdown calls below are actually reached and buffered spans/metrics get flushed
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+
+    // shutting down both providers to ensure all spans and metrics are flushed.
+    tp.shutdown().expect("TracerProvider should shutdown successfully");
+    mp.shutdown().expect("MeterProvider should shutdown successfully");
+}
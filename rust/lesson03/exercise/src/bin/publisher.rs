@@ -2,6 +2,8 @@ use axum::{extract::Query, routing::get, Router};
 use tokio::net::TcpListener;
 use std::collections::HashMap;
 
+use exercise::{init_meter, init_tracer, middleware::OpenTelemetryLayer, shutdown_signal};
+
 async fn publish_handler(Query(params): Query<HashMap<String, String>>) {
     if let Some(hello_str)  = params.get("hello_str"){
         println!("{}", hello_str);
@@ -12,9 +14,27 @@ async fn publish_handler(Query(params): Query<HashMap<String, String>>) {
 
 #[tokio::main]
 async fn main() {
-    let app = Router::new().route("/publish", get(publish_handler));
-    
+    // initializing the OpenTelemetry TracerProvider and MeterProvider with the service name
+    // "publisher"; `OpenTelemetryLayer` records request count/duration through the meter
+    let tp = init_tracer("publisher")
+        .expect("Error initializing tracer");
+    let mp = init_meter("publisher")
+        .expect("Error initializing meter");
+
+    let app = Router::new()
+        .route("/publish", get(publish_handler))
+        .route_layer(OpenTelemetryLayer::new());
+
     let listener = TcpListener::bind("0.0.0.0:8082").await.unwrap();
-    
-    axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+
+    // draining in-flight requests on SIGINT/SIGTERM instead of blocking forever, so the
+    // shutdown calls below are actually reached and buffered spans/metrics get flushed
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+
+    // shutting down both providers to ensure all spans and metrics are flushed.
+    tp.shutdown().expect("TracerProvider should shutdown successfully");
+    mp.shutdown().expect("MeterProvider should shutdown successfully");
+}
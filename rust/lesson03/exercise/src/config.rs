@@ -0,0 +1,193 @@
+use std::env;
+
+use opentelemetry::{
+    global,
+    propagation::{TextMapCompositePropagator, TextMapPropagator},
+    trace::TraceError,
+    KeyValue,
+};
+use opentelemetry_sdk::{
+    propagation::{BaggagePropagator, TraceContextPropagator},
+    trace::SdkTracerProvider,
+    Resource,
+};
+use opentelemetry_otlp::WithExportConfig;
+
+/// which wire protocol the OTLP span exporter should speak.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+    /// OTLP over HTTP with binary protobuf bodies.
+    HttpBinary,
+    /// OTLP over gRPC.
+    Grpc,
+}
+
+/// which text-map propagator(s) should be registered globally for context propagation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Propagation {
+    /// the W3C `traceparent`/`tracestate` format.
+    W3c,
+    /// the Jaeger single-header `uber-trace-id` format, for interop with peers that don't
+    /// speak W3C yet.
+    Jaeger,
+    /// W3C TraceContext, W3C Baggage, and Jaeger all registered together.
+    Composite,
+    /// composed from the standard `OTEL_PROPAGATORS` env var at build time, so the set of
+    /// propagators can be changed without a code change or recompile.
+    Env,
+}
+
+/// configuration for [`TracerConfig::build`], letting callers pick the exporter protocol,
+/// endpoint, propagation format, and resource attributes instead of the fixed defaults
+/// baked into [`crate::init_tracer`].
+pub struct TracerConfig {
+    pub service_name: String,
+    pub protocol: Protocol,
+    pub propagation: Propagation,
+    pub endpoint: String,
+    pub headers: Vec<(String, String)>,
+    pub resource_attributes: Vec<KeyValue>,
+}
+
+impl TracerConfig {
+    /// starts from `default_endpoint`, overriding the endpoint, protocol, headers and
+    /// service name from the standard `OTEL_EXPORTER_OTLP_ENDPOINT`,
+    /// `OTEL_EXPORTER_OTLP_PROTOCOL`, `OTEL_EXPORTER_OTLP_HEADERS` and `OTEL_SERVICE_NAME`
+    /// env vars when set, so the same binary can be pointed at any modern OTLP collector
+    /// (gRPC or HTTP) without a recompile.
+    pub fn from_env(service_name: &str, default_endpoint: &str) -> Self {
+        let endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .unwrap_or_else(|_| default_endpoint.to_string());
+        let service_name = env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| service_name.to_string());
+        let protocol = match env::var("OTEL_EXPORTER_OTLP_PROTOCOL").as_deref() {
+            Ok("grpc") => Protocol::Grpc,
+            _ => Protocol::HttpBinary,
+        };
+        let headers = env::var("OTEL_EXPORTER_OTLP_HEADERS")
+            .map(|value| parse_headers(&value))
+            .unwrap_or_default();
+
+        Self {
+            service_name,
+            protocol,
+            propagation: Propagation::Env,
+            endpoint,
+            headers,
+            resource_attributes: vec![
+                KeyValue::new("service.version", "1.0.0"),
+                KeyValue::new("deployment.environment", "production"),
+            ],
+        }
+    }
+
+    pub fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    pub fn with_propagation(mut self, propagation: Propagation) -> Self {
+        self.propagation = propagation;
+        self
+    }
+
+    pub fn with_headers(mut self, headers: Vec<(String, String)>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    pub fn with_resource_attributes(mut self, attributes: Vec<KeyValue>) -> Self {
+        self.resource_attributes = attributes;
+        self
+    }
+
+    /// builds the exporter, tracer provider and propagator described by this config,
+    /// registers the provider and propagator globally, and returns the provider so the
+    /// caller can `shutdown()` it on exit.
+    pub fn build(self) -> Result<SdkTracerProvider, TraceError> {
+        let headers: std::collections::HashMap<String, String> = self.headers.into_iter().collect();
+
+        // building the OTLP span exporter for the selected protocol
+        let exporter = match self.protocol {
+            Protocol::HttpBinary => opentelemetry_otlp::SpanExporter::builder()
+                .with_http()
+                .with_endpoint(&self.endpoint)
+                .with_headers(headers)
+                .build()?,
+            Protocol::Grpc => opentelemetry_otlp::SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(&self.endpoint)
+                .with_headers(headers)
+                .build()?,
+        };
+
+        // defining resource attributes for the service, merging the caller's extras in
+        // alongside the service name
+        let mut attributes = vec![KeyValue::new("service.name", self.service_name.clone())];
+        attributes.extend(self.resource_attributes);
+        let resource = Resource::builder_empty().with_attributes(attributes).build();
+
+        // creating a TracerProvider with a batch span processor on the tokio runtime, using
+        // the selected exporter and resource attributes
+        let tp = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource)
+            .build();
+
+        global::set_tracer_provider(tp.clone());
+        global::set_text_map_propagator(build_propagator(self.propagation));
+
+        Ok(tp)
+    }
+}
+
+/// parses the standard `OTEL_EXPORTER_OTLP_HEADERS` format: a comma separated list of
+/// `key=value` pairs, e.g. `"api-key=secret,x-tenant=acme"`.
+fn parse_headers(value: &str) -> Vec<(String, String)> {
+    value
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// constructs the text-map propagator selected by `propagation`.
+fn build_propagator(propagation: Propagation) -> TextMapCompositePropagator {
+    match propagation {
+        Propagation::W3c => TextMapCompositePropagator::new(vec![Box::new(TraceContextPropagator::new())]),
+        Propagation::Jaeger => {
+            TextMapCompositePropagator::new(vec![Box::new(opentelemetry_jaeger_propagator::Propagator::new())])
+        }
+        Propagation::Composite => TextMapCompositePropagator::new(vec![
+            Box::new(TraceContextPropagator::new()),
+            Box::new(BaggagePropagator::new()),
+            Box::new(opentelemetry_jaeger_propagator::Propagator::new()),
+        ]),
+        Propagation::Env => propagator_from_env(),
+    }
+}
+
+/// builds a composite propagator from the standard `OTEL_PROPAGATORS` env var: a comma
+/// separated list drawn from `tracecontext`, `baggage`, and `jaeger` (the Jaeger single
+/// header `uber-trace-id` format, with `uberctx-` prefixed baggage keys). Defaults to
+/// `tracecontext` alone when the var is unset. Entries it doesn't recognize are skipped
+/// with a warning, and `tracecontext` is used when no entry is recognized at all, so a
+/// typo doesn't silently drop propagation entirely.
+pub fn propagator_from_env() -> TextMapCompositePropagator {
+    let value = env::var("OTEL_PROPAGATORS").unwrap_or_else(|_| "tracecontext".to_string());
+
+    let mut propagators: Vec<Box<dyn TextMapPropagator + Send + Sync>> = Vec::new();
+    for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        match name {
+            "tracecontext" => propagators.push(Box::new(TraceContextPropagator::new())),
+            "baggage" => propagators.push(Box::new(BaggagePropagator::new())),
+            "jaeger" => propagators.push(Box::new(opentelemetry_jaeger_propagator::Propagator::new())),
+            other => eprintln!("warning: unknown propagator '{other}' in OTEL_PROPAGATORS, ignoring"),
+        }
+    }
+
+    if propagators.is_empty() {
+        propagators.push(Box::new(TraceContextPropagator::new()));
+    }
+
+    TextMapCompositePropagator::new(propagators)
+}
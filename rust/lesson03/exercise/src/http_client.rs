@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use opentelemetry::{
+    global,
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use reqwest::Client;
+
+use crate::inject_context;
+
+/// performs a traced GET request: starts a `SpanKind::Client` span as a child of `parent_cx`,
+/// sets the HTTP semantic-convention attributes, injects the span context into the outgoing
+/// request headers, awaits the response, records the outcome, and ends the span before
+/// returning — so call sites no longer need to build the request, grab `headers_mut()`,
+/// inject the context, and remember to end the span on every branch themselves.
+pub async fn traced_get(
+    client: &Client,
+    url: &str,
+    params: &HashMap<String, String>,
+    parent_cx: &Context,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let tracer = global::tracer("http-client");
+    let span = tracer
+        .span_builder(url.to_string())
+        .with_kind(SpanKind::Client)
+        .start_with_context(&tracer, parent_cx);
+
+    let cx = parent_cx.with_span(span);
+    cx.span().set_attribute(KeyValue::new("http.request.method", "GET"));
+    cx.span().set_attribute(KeyValue::new("url.full", url.to_string()));
+
+    let result = async {
+        let mut req = client.get(url).query(params).build()?;
+        inject_context(&cx, req.headers_mut());
+        client.execute(req).await
+    }
+    .await;
+
+    match &result {
+        Ok(resp) => {
+            cx.span().set_attribute(KeyValue::new("http.response.status_code", resp.status().as_u16() as i64));
+            if resp.status().is_server_error() {
+                cx.span().set_status(Status::error(resp.status().to_string()));
+            }
+        }
+        Err(err) => {
+            cx.span().record_error(err);
+            cx.span().set_status(Status::error(err.to_string()));
+        }
+    }
+
+    cx.span().end();
+    result
+}
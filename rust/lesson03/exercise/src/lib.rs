@@ -1,13 +1,20 @@
-// use tracing::instrument;
-use opentelemetry::{
-    global, trace::TraceError, KeyValue
-};
-use opentelemetry_sdk::{
-    propagation::TraceContextPropagator, trace::SdkTracerProvider, Resource
-};
-use opentelemetry_otlp::WithExportConfig;
-
-
+use opentelemetry::{global, trace::TraceError, Context};
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_http::HeaderInjector;
+use reqwest::header::HeaderMap;
+
+pub mod middleware;
+pub mod config;
+pub mod tracing_support;
+pub mod http_client;
+pub mod metrics;
+pub mod shutdown;
+
+pub use config::{propagator_from_env, Propagation, Protocol, TracerConfig};
+pub use tracing_support::init_tracer_with_tracing;
+pub use http_client::traced_get;
+pub use metrics::{init_meter, init_meter_with_backend};
+pub use shutdown::shutdown_signal;
 
 const TRACING_BACKEND: &str = "http://192.168.50.4:4318/v1/traces";
 
@@ -16,36 +23,23 @@ pub fn init_tracer(service: &str) -> Result<SdkTracerProvider, TraceError>{
     init_tracer_with_backend(service, TRACING_BACKEND)
 }
 
-/// initializes the OpenTelemetry Tracer with the specified service name and backend.
+/// initializes the OpenTelemetry Tracer with the specified service name and backend, going
+/// through [`TracerConfig::from_env`] and [`TracerConfig::build`] so the pluggable exporter
+/// protocol, headers, endpoint and propagator selection they implement (including the
+/// Jaeger `uber-trace-id` format) are actually in effect for the three services that call
+/// this function, instead of being reachable only by constructing a `TracerConfig` by hand.
+/// `backend` is used as the default endpoint, but `OTEL_EXPORTER_OTLP_ENDPOINT`,
+/// `OTEL_EXPORTER_OTLP_PROTOCOL` and `OTEL_EXPORTER_OTLP_HEADERS` all take precedence when
+/// set, so the same binary can point at any modern OTLP collector without a recompile.
 pub fn init_tracer_with_backend(service: &str, backend: &str) -> Result<SdkTracerProvider, TraceError>{
+    TracerConfig::from_env(service, backend).build()
+}
 
-    // creating an OTLP trace exporter to send spans using HTTP to the specified backend
-    let exporter = opentelemetry_otlp::SpanExporter::builder().with_http().with_endpoint(backend).build()?;
-
-    // defining resource attributes for the service
-    let resource = Resource::builder_empty()
-        .with_attributes([
-                // service name
-                KeyValue::new("service.name", service.to_string()),
-                // version number of the environment
-                KeyValue::new("service.version", "1.0.0".to_string()),
-                // environment
-                KeyValue::new("environment", "production".to_string()),
-            ])
-        .build();
-
-    // creating a TracerProvider with the specified exporter and resource attributes
-    let tp = SdkTracerProvider::builder()
-        .with_batch_exporter(exporter)
-        .with_resource(resource)
-        .build();
-
-    // setting up the global tracer provider
-    global::set_tracer_provider(tp.clone());
-    
-
-    // Set up a propagator to handle context propagation across services.
-    global::set_text_map_propagator(TraceContextPropagator::new());
-
-    Ok(tp)
+/// injects the span context carried by `cx` into `headers` using the globally configured
+/// text-map propagator, so that a downstream service's `HeaderExtractor` can rebuild the
+/// same trace instead of starting a disconnected root span.
+pub fn inject_context(cx: &Context, headers: &mut HeaderMap) {
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(cx, &mut HeaderInjector(headers))
+    });
 }
\ No newline at end of file
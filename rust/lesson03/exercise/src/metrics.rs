@@ -0,0 +1,42 @@
+use opentelemetry::{global, metrics::MetricError, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{metrics::SdkMeterProvider, Resource};
+
+/// default OTLP/HTTP metrics endpoint. Metrics and traces are exported on different paths
+/// under the same collector (`/v1/metrics` vs `/v1/traces`), so this can't reuse
+/// [`crate::TRACING_BACKEND`] as-is.
+const METRICS_BACKEND: &str = "http://192.168.50.4:4318/v1/metrics";
+
+/// initializes the OpenTelemetry MeterProvider with the specified service name and default
+/// backend, mirroring [`crate::init_tracer`] so the two pipelines share the same collector
+/// host and resource attributes by default.
+pub fn init_meter(service: &str) -> Result<SdkMeterProvider, MetricError> {
+    init_meter_with_backend(service, METRICS_BACKEND)
+}
+
+/// initializes the OpenTelemetry MeterProvider with the specified service name and backend.
+pub fn init_meter_with_backend(service: &str, backend: &str) -> Result<SdkMeterProvider, MetricError> {
+    // creating an OTLP metric exporter to send metrics using HTTP to the specified backend
+    let exporter = opentelemetry_otlp::MetricExporter::builder().with_http().with_endpoint(backend).build()?;
+
+    // defining resource attributes for the service
+    let resource = Resource::builder_empty()
+        .with_attributes([
+            KeyValue::new("service.name", service.to_string()),
+            KeyValue::new("service.version", "1.0.0"),
+            KeyValue::new("deployment.environment", "production"),
+        ])
+        .build();
+
+    // creating a MeterProvider that exports on a periodic interval, with the specified
+    // exporter and resource attributes
+    let mp = SdkMeterProvider::builder()
+        .with_periodic_exporter(exporter)
+        .with_resource(resource)
+        .build();
+
+    // setting up the global meter provider
+    global::set_meter_provider(mp.clone());
+
+    Ok(mp)
+}
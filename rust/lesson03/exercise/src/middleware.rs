@@ -0,0 +1,149 @@
+use std::{
+    task::{Context as TaskContext, Poll},
+    time::Instant,
+};
+
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{header::HOST, Request, Response},
+};
+use futures_util::future::BoxFuture;
+use opentelemetry::{
+    global,
+    metrics::{Counter, Histogram},
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer},
+    KeyValue,
+};
+use opentelemetry_http::HeaderExtractor;
+use tower::{Layer, Service};
+
+/// a `tower::Layer` that wraps an axum `Router` with server-side OpenTelemetry tracing and
+/// RED metrics, so individual handlers no longer need to hand-extract context, start a
+/// span, set HTTP attributes, record request/duration metrics, and end the span themselves.
+#[derive(Clone)]
+pub struct OpenTelemetryLayer {
+    request_count: Counter<u64>,
+    request_duration: Histogram<f64>,
+}
+
+impl Default for OpenTelemetryLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenTelemetryLayer {
+    pub fn new() -> Self {
+        let meter = global::meter("http-server");
+        Self {
+            request_count: meter.u64_counter("http.server.request.count").build(),
+            request_duration: meter.f64_histogram("http.server.duration").with_unit("ms").build(),
+        }
+    }
+}
+
+impl<S> Layer<S> for OpenTelemetryLayer {
+    type Service = OpenTelemetryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        OpenTelemetryService {
+            inner,
+            request_count: self.request_count.clone(),
+            request_duration: self.request_duration.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpenTelemetryService<S> {
+    inner: S,
+    request_count: Counter<u64>,
+    request_duration: Histogram<f64>,
+}
+
+impl<S> Service<Request<Body>> for OpenTelemetryService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: std::fmt::Display,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // extracting the upstream span context from the request headers
+        let parent_cx = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(req.headers()))
+        });
+
+        // the route pattern axum matched (e.g. "/format"), falling back to the raw path if
+        // this layer is ever attached with `Router::layer` instead of `Router::route_layer` —
+        // `Router::layer` wraps the whole router *before* route matching runs, so
+        // `MatchedPath` would never be populated at this point. Always attach this layer with
+        // `.route_layer(...)`, not `.layer(...)`, or every span/route attribute below falls
+        // back to the raw path on every request.
+        let route = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|matched| matched.as_str().to_string())
+            .unwrap_or_else(|| req.uri().path().to_string());
+
+        // starting a new span as a child of the extracted context, named after the route
+        let tracer = global::tracer("http-server");
+        let span = tracer
+            .span_builder(route.clone())
+            .with_kind(SpanKind::Server)
+            .start_with_context(&tracer, &parent_cx);
+
+        let span_cx = parent_cx.with_span(span);
+        span_cx.span().set_attribute(KeyValue::new("http.request.method", req.method().to_string()));
+        span_cx.span().set_attribute(KeyValue::new("url.path", req.uri().path().to_string()));
+        span_cx.span().set_attribute(KeyValue::new("http.route", route.clone()));
+        if let Some(host) = req.headers().get(HOST).and_then(|value| value.to_str().ok()) {
+            span_cx.span().set_attribute(KeyValue::new("server.address", host.to_string()));
+        }
+
+        // making `span_cx` the active context for the handler, so it can look it up via
+        // `opentelemetry::Context::current()` instead of receiving it as an argument
+        let future = self.inner.clone().call(req).with_context(span_cx.clone());
+
+        let start = Instant::now();
+        let route_attr = KeyValue::new("http.route", route);
+        let request_count = self.request_count.clone();
+        let request_duration = self.request_duration.clone();
+
+        Box::pin(async move {
+            let result = future.await;
+
+            let status_attr = match &result {
+                Ok(resp) => {
+                    let status = resp.status();
+                    span_cx.span().set_attribute(KeyValue::new("http.response.status_code", status.as_u16() as i64));
+                    if status.is_server_error() {
+                        span_cx.span().set_status(Status::error(status.to_string()));
+                    }
+                    KeyValue::new("http.response.status_code", status.as_u16() as i64)
+                }
+                Err(err) => {
+                    span_cx.span().set_status(Status::error(err.to_string()));
+                    KeyValue::new("http.response.status_code", 0i64)
+                }
+            };
+
+            // recording the request count and duration, correlated with this span via the
+            // same `http.route` / status attributes
+            let attrs = [route_attr, status_attr];
+            request_count.add(1, &attrs);
+            request_duration.record(start.elapsed().as_secs_f64() * 1000.0, &attrs);
+
+            span_cx.span().end();
+            result
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use opentelemetry::trace::{TraceError, TracerProvider};
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use crate::{init_tracer_with_backend, TRACING_BACKEND};
+
+/// initializes the OpenTelemetry Tracer exactly like [`crate::init_tracer`], but also
+/// installs a `tracing_subscriber::Registry` bridged to it via `tracing-opentelemetry`, so
+/// `#[tracing::instrument]` functions and `tracing::info!`/`error!` events turn into OTel
+/// spans and span events automatically, instead of every call site writing its own
+/// `tracer.start(...)` / `span.add_event(...)` / `span.end()`.
+pub fn init_tracer_with_tracing(service: &str) -> Result<SdkTracerProvider, TraceError> {
+    init_tracer_with_tracing_and_backend(service, TRACING_BACKEND)
+}
+
+/// same as [`init_tracer_with_tracing`], but against an explicit backend endpoint.
+pub fn init_tracer_with_tracing_and_backend(service: &str, backend: &str) -> Result<SdkTracerProvider, TraceError> {
+    let tp = init_tracer_with_backend(service, backend)?;
+
+    // bridging the registered tracer provider into a `tracing` subscriber layer, so spans
+    // declared with `#[tracing::instrument]` are exported through it
+    let tracer = tp.tracer(service.to_string());
+    let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+
+    tracing_subscriber::registry()
+        .with(otel_layer)
+        .init();
+
+    Ok(tp)
+}
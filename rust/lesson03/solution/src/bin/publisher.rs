@@ -1,36 +1,26 @@
 use std::collections::HashMap;
 use axum::{
     extract::Query,
-    http::HeaderMap,
     routing::get,
     Router,
 };
 use tokio::net::TcpListener;
 
-use opentelemetry::{
-    global, trace::{Span, Tracer},
-};
-use opentelemetry_http::HeaderExtractor;
+use opentelemetry::{trace::TraceContextExt, Context};
 
-use exercise::init_tracer;
+use exercise::{init_meter, init_tracer, middleware::OpenTelemetryLayer, shutdown_signal};
 
 
-async fn publish_handler(Query(params): Query<HashMap<String, String>>,  headers: HeaderMap) {
-    // creating a named instance of Tracer via the configured GlobalTracerProvider
-    let tracer = global::tracer("publisher-tracer");
-    
-    // extracting the span context from the request headers
-    let cx = global::get_text_map_propagator(|propagator| {
-        propagator.extract(&HeaderExtractor(&headers))
-    });
-    
-    // starting a new span named "publish" as a child of the extracted span context
-    let mut span = tracer.start_with_context("publish", &cx);
+async fn publish_handler(Query(params): Query<HashMap<String, String>>) {
+    // `OpenTelemetryLayer` already extracted the upstream context and started the span for
+    // this request; pull it from the current context instead of doing that by hand
+    let cx = Context::current();
 
     if let Some(hello_str) = params.get("hello_str") {
         println!("{}", hello_str);
     }
-    span.end();
+
+    cx.span().add_event("publish-event", vec![]);
 }
 
 
@@ -38,17 +28,27 @@ async fn publish_handler(Query(params): Query<HashMap<String, String>>,  headers
 #[tokio::main]
 async fn main() {
 
-    // initializing the OpenTelemetry TracerProvider with the service name "publisher"
+    // initializing the OpenTelemetry TracerProvider and MeterProvider with the service name
+    // "publisher"; `OpenTelemetryLayer` records request count/duration through the meter
     let tp = init_tracer("publisher")
         .expect("Error initializing tracer");
+    let mp = init_meter("publisher")
+        .expect("Error initializing meter");
+
+    let app = Router::new()
+        .route("/publish", get(publish_handler))
+        .route_layer(OpenTelemetryLayer::new());
 
-    let app = Router::new().route("/publish", get(publish_handler));
-    
     let listener = TcpListener::bind("0.0.0.0:8082").await.unwrap();
-    
-    let _ = axum::serve(listener, app).await;
-    
-    // shutting down the tracer provider to ensure all spans are flushed.
+
+    // draining in-flight requests on SIGINT/SIGTERM instead of blocking forever, so the
+    // shutdown calls below are actually reached and buffered spans/metrics get flushed
+    let _ = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await;
+
+    // shutting down both providers to ensure all spans and metrics are flushed.
     tp.shutdown().expect("TracerProvider should shutdown successfully");
+    mp.shutdown().expect("MeterProvider should shutdown successfully");
 
-}
\ No newline at end of file
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use exercise::init_tracer;
 
 use opentelemetry::{
-    global, trace::{Span, TraceContextExt, Tracer, FutureExt}, KeyValue,
+    baggage::BaggageExt, global, trace::{Span, TraceContextExt, Tracer, FutureExt}, KeyValue,
 };
 
 use opentelemetry_http::HeaderInjector;
@@ -14,12 +14,14 @@ const PUBLISH_URL: &str = "http://localhost:8082/publish";
 
 #[tokio::main]
 async fn main()-> Result<(), reqwest::Error>{
-    // checking if the number of command-line arguments is exactly 2 (program name and one argument).
+    // expecting the name to greet, and optionally a custom greeting (e.g. "Howdy") as a
+    // second argument; the formatter falls back to its own default when it's omitted.
     let args: Vec<_> = env::args().collect();
-    if args.len() != 2 {
-        panic!("ERROR: Expecting one argument");
+    if args.len() != 2 && args.len() != 3 {
+        panic!("ERROR: Expecting one argument, and optionally a greeting as a second argument");
     }
     let hello_to = args[1].clone();
+    let greeting = args.get(2).cloned();
 
     // initializing the OpenTelemetry TracerProvider with the service name "hello-world"
     let tp = init_tracer("hello-world")
@@ -34,8 +36,18 @@ async fn main()-> Result<(), reqwest::Error>{
     // adding an attribute to the span
     span.set_attribute(KeyValue::new("hello-to", hello_to.to_string()));
 
-    // getting the context with the current span included
-    let context_main = opentelemetry::Context::default().with_span(span);
+    // getting the context with the current span included, and attaching baggage members
+    // describing where the request came from and, if the caller passed one, their custom
+    // greeting verbatim, so downstream services can read them back out of the propagated
+    // context. Omitting "greeting" when no override was given lets a formatter's own
+    // language-based localization (see `format_handler`) run instead of always overriding it.
+    let mut baggage = vec![KeyValue::new("request.origin", "cli")];
+    if let Some(greeting) = greeting {
+        baggage.push(KeyValue::new("greeting", greeting));
+    }
+    let context_main = opentelemetry::Context::default()
+        .with_span(span)
+        .with_baggage(baggage);
 
     // calling the `format_string` function with the span context `context_main` to maintain proper parent-child relationship
     let formatted_str = FutureExt::with_context(format_string(&hello_to), context_main.clone()).await?;
@@ -1,46 +1,67 @@
 use std::collections::HashMap;
 use axum::{
     extract::Query,
-    http::HeaderMap,
     routing::get,
     Router,
 };
 use tokio::net::TcpListener;
 
-use opentelemetry::{
-    global, trace::{Span, Tracer}, KeyValue,
-};
-use opentelemetry_http::HeaderExtractor;
-
-use exercise::init_tracer;
-
-async fn format_handler(Query(params): Query<HashMap<String, String>>,  headers: HeaderMap) -> String {
-    
-    // creating a named instance of Tracer via the configured GlobalTracerProvider
-    let tracer = global::tracer("formatter-tracer");
-    
-    // extracting the span context from the request headers
-    let cx = global::get_text_map_propagator(|propagator| {
-        propagator.extract(&HeaderExtractor(&headers))
-    });
-    
-    // starting a new span named "format" as a child of the extracted span context
-    let mut span = tracer.start_with_context("format", &cx);
-   
-    let mut resp = "Hello, !".to_string();
+use opentelemetry::{baggage::BaggageExt, trace::TraceContextExt, Context, KeyValue};
+
+use exercise::{init_tracer, middleware::OpenTelemetryLayer, shutdown_signal};
+
+/// the greetings this service knows how to say, keyed by a `greeting.language` baggage
+/// member; defaults to English when the member is absent or set to anything unrecognized.
+/// Only consulted when the caller hasn't already set an explicit `"greeting"` override (see
+/// `format_handler`) — the client in this exercise doesn't send `greeting.language` today, so
+/// this runs on its "en" default whenever an override is omitted.
+fn localized_greeting(language: &str) -> &'static str {
+    match language {
+        "es" => "Hola",
+        "fr" => "Bonjour",
+        _ => "Hello",
+    }
+}
+
+async fn format_handler(Query(params): Query<HashMap<String, String>>) -> String {
+    // `OpenTelemetryLayer` already extracted the upstream context and started the span for
+    // this request; pull it from the current context instead of doing that by hand
+    let cx = Context::current();
+
+    // reading the baggage the client attached: an optional explicit greeting override, an
+    // optional language to localize by, and where the request originated
+    let baggage = cx.baggage();
+    let language = baggage.get("greeting.language").map(|v| v.to_string()).unwrap_or_else(|| "en".to_string());
+    let origin = baggage.get("request.origin").map(|v| v.to_string());
+
+    // an explicit "greeting" member (the contract the `lesson04/solution` formatter already
+    // implements) takes priority over language-based localization, so a caller that sets it
+    // directly still gets exactly the word it asked for
+    let greeting = baggage
+        .get("greeting")
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| localized_greeting(&language).to_string());
+
+    // copying the baggage members onto the span as attributes, so they show up alongside
+    // the rest of this request's trace instead of only being visible to the code that reads
+    // baggage directly
+    cx.span().set_attribute(KeyValue::new("greeting.language", language));
+    if let Some(origin) = origin {
+        cx.span().set_attribute(KeyValue::new("request.origin", origin));
+    }
+
+    let mut resp = format!("{}, !", greeting);
 
     if let Some(hello_to) = params.get("hello_to") {
-        resp = format!("Hello, {}!", hello_to);
+        resp = format!("{}, {}!", greeting, hello_to);
     }
 
     // adding an event to the span indicating that the string was properly formatted
-    span.add_event("format-event-response", 
+    cx.span().add_event("format-event-response",
     vec![
         KeyValue::new("format-response", format!("string-formated: {}", resp)),
         ]
     );
-    
-    Span::end(&mut span);
 
     resp
 }
@@ -53,12 +74,19 @@ async fn main() {
     let tp = init_tracer("formatter")
         .expect("Error initializing tracer");
 
-    let app = Router::new().route("/format", get(format_handler));
-    
+    let app = Router::new()
+        .route("/format", get(format_handler))
+        .route_layer(OpenTelemetryLayer::new());
+
     let listener = TcpListener::bind("0.0.0.0:8081").await.unwrap();
-    
-    axum::serve(listener, app).await.unwrap();
+
+    // draining in-flight requests on SIGINT/SIGTERM instead of blocking forever, so the
+    // shutdown call below is actually reached and buffered spans get flushed
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
 
     // shutting down the tracer provider to ensure all spans are flushed.
     tp.shutdown().expect("TracerProvider should shutdown successfully");
-}
\ No newline at end of file
+}
@@ -0,0 +1,113 @@
+use std::task::{Context as TaskContext, Poll};
+
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{header::HOST, Request, Response},
+};
+use futures_util::future::BoxFuture;
+use opentelemetry::{
+    global,
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer},
+    KeyValue,
+};
+use opentelemetry_http::HeaderExtractor;
+use tower::{Layer, Service};
+
+/// a `tower::Layer` that wraps an axum `Router` with server-side OpenTelemetry tracing, so
+/// individual handlers no longer need to hand-extract context, start a span, set HTTP
+/// attributes, and end the span themselves.
+#[derive(Clone, Default)]
+pub struct OpenTelemetryLayer;
+
+impl OpenTelemetryLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for OpenTelemetryLayer {
+    type Service = OpenTelemetryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        OpenTelemetryService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpenTelemetryService<S> {
+    inner: S,
+}
+
+impl<S> Service<Request<Body>> for OpenTelemetryService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: std::fmt::Display,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // extracting the upstream span context from the request headers
+        let parent_cx = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(req.headers()))
+        });
+
+        // the route pattern axum matched (e.g. "/format"), falling back to the raw path if
+        // this layer is ever attached with `Router::layer` instead of `Router::route_layer` —
+        // `Router::layer` wraps the whole router *before* route matching runs, so
+        // `MatchedPath` would never be populated at this point. Always attach this layer with
+        // `.route_layer(...)`, not `.layer(...)`, or every span/route attribute below falls
+        // back to the raw path on every request.
+        let route = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|matched| matched.as_str().to_string())
+            .unwrap_or_else(|| req.uri().path().to_string());
+
+        // starting a new span as a child of the extracted context, named after the route
+        let tracer = global::tracer("http-server");
+        let span = tracer
+            .span_builder(route.clone())
+            .with_kind(SpanKind::Server)
+            .start_with_context(&tracer, &parent_cx);
+
+        let span_cx = parent_cx.with_span(span);
+        span_cx.span().set_attribute(KeyValue::new("http.request.method", req.method().to_string()));
+        span_cx.span().set_attribute(KeyValue::new("url.path", req.uri().path().to_string()));
+        span_cx.span().set_attribute(KeyValue::new("http.route", route));
+        if let Some(host) = req.headers().get(HOST).and_then(|value| value.to_str().ok()) {
+            span_cx.span().set_attribute(KeyValue::new("server.address", host.to_string()));
+        }
+
+        // making `span_cx` the active context for the handler, so it can look it up via
+        // `opentelemetry::Context::current()` instead of receiving it as an argument
+        let future = self.inner.clone().call(req).with_context(span_cx.clone());
+
+        Box::pin(async move {
+            let result = future.await;
+
+            match &result {
+                Ok(resp) => {
+                    let status = resp.status();
+                    span_cx.span().set_attribute(KeyValue::new("http.response.status_code", status.as_u16() as i64));
+                    if status.is_server_error() {
+                        span_cx.span().set_status(Status::error(status.to_string()));
+                    }
+                }
+                Err(err) => {
+                    span_cx.span().set_status(Status::error(err.to_string()));
+                }
+            }
+
+            span_cx.span().end();
+            result
+        })
+    }
+}
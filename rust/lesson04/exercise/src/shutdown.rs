@@ -0,0 +1,27 @@
+use tokio::signal;
+
+/// resolves once SIGINT (Ctrl-C) or, on Unix, SIGTERM is received. Pass it to
+/// `axum::serve(...).with_graceful_shutdown(...)` so in-flight requests are drained before
+/// the tracer provider is shut down; without it `axum::serve` blocks forever and a killed
+/// process loses whatever spans were still buffered.
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install SIGINT handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
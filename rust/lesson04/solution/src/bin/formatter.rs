@@ -1,28 +1,21 @@
 use std::collections::HashMap;
 use axum::{
     extract::Query,
-    http::HeaderMap,
     routing::get,
     Router,
 };
 use tokio::net::TcpListener;
 
 use opentelemetry::{
-    baggage::BaggageExt, global, trace::{Span, Tracer}, KeyValue
+    baggage::BaggageExt, trace::TraceContextExt, Context, KeyValue
 };
-use opentelemetry_http::HeaderExtractor;
 
-use exercise::init_tracer;
+use exercise::{init_tracer, middleware::OpenTelemetryLayer, shutdown_signal};
 
-async fn format_handler(Query(params): Query<HashMap<String, String>>,  headers: HeaderMap) -> String {
-    
-    // creating a named instance of Tracer via the configured GlobalTracerProvider
-    let tracer = global::tracer("formatter-tracer");
-    
-    // extracting the span context from the request headers
-    let cx = global::get_text_map_propagator(|propagator| {
-        propagator.extract(&HeaderExtractor(&headers))
-    });
+async fn format_handler(Query(params): Query<HashMap<String, String>>) -> String {
+    // `OpenTelemetryLayer` already extracted the upstream context and started the span for
+    // this request; pull it from the current context instead of doing that by hand
+    let cx = Context::current();
 
     // extracting baggage from the context
     let baggage = cx.baggage();
@@ -33,9 +26,10 @@ async fn format_handler(Query(params): Query<HashMap<String, String>>,  headers:
 	if let Some(greeting_) = baggage.get("greeting"){
         greeting = greeting_.to_string();
     };
-    
-    // starting a new span named "format" as a child of the extracted span context
-    let mut span = tracer.start_with_context("format", &cx);
+
+    // copying the baggage member onto the span as an attribute, so it's visible alongside
+    // the rest of this request's trace and not only to code that reads baggage directly
+    cx.span().set_attribute(KeyValue::new("greeting", greeting.clone()));
 
     let mut resp= format!("{} there!", greeting);
 
@@ -44,13 +38,11 @@ async fn format_handler(Query(params): Query<HashMap<String, String>>,  headers:
     }
 
     // adding an event to the span indicating that the string was properly formatted
-    span.add_event("format-event-response", 
+    cx.span().add_event("format-event-response",
     vec![
         KeyValue::new("format-response", format!("string-formated: {}", resp)),
         ]
     );
-    
-    Span::end(&mut span);
 
     resp
 }
@@ -63,12 +55,19 @@ async fn main() {
     let tp = init_tracer("formatter")
         .expect("Error initializing tracer");
 
-    let app = Router::new().route("/format", get(format_handler));
-    
+    let app = Router::new()
+        .route("/format", get(format_handler))
+        .route_layer(OpenTelemetryLayer::new());
+
     let listener = TcpListener::bind("0.0.0.0:8081").await.unwrap();
-    
-    axum::serve(listener, app).await.unwrap();
+
+    // draining in-flight requests on SIGINT/SIGTERM instead of blocking forever, so the
+    // shutdown call below is actually reached and buffered spans get flushed
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
 
     // shutting down the tracer provider to ensure all spans are flushed.
     tp.shutdown().expect("TracerProvider should shutdown successfully");
-}
\ No newline at end of file
+}
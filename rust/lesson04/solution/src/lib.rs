@@ -10,6 +10,11 @@ use opentelemetry_otlp::WithExportConfig;
 
 
 
+pub mod middleware;
+pub mod shutdown;
+
+pub use shutdown::shutdown_signal;
+
 const TRACING_BACKEND: &str = "http://192.168.50.4:4318/v1/traces";
 
 /// initializes the OpenTelemetry Tracer with the specified service name and default backend.